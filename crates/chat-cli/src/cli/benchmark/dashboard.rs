@@ -0,0 +1,328 @@
+use crossterm::event::{
+    self,
+    Event,
+    KeyCode,
+};
+use crossterm::terminal::{
+    EnterAlternateScreen,
+    LeaveAlternateScreen,
+};
+use crossterm::{
+    cursor,
+    execute,
+    queue,
+    style,
+    terminal,
+};
+use std::io::{
+    Stdout,
+    Write,
+    stdout,
+};
+use std::time::Duration;
+
+use tokio::sync::mpsc::UnboundedReceiver;
+
+use super::runner::{
+    BenchmarkError,
+    BenchmarkEvent,
+    BenchmarkSummary,
+};
+use crate::cli::chat::performance::{
+    LatencyHistogram,
+    PerformanceMetrics,
+};
+
+const SPARKLINE_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const TICK_RATE: Duration = Duration::from_millis(120);
+
+/// Per-model samples the dashboard keeps around so each tab can redraw
+/// independently of the others. Keeping the full `(metrics, histogram)` pair
+/// per run (rather than just the scalars the charts render) is what lets
+/// `partial_summary` rebuild a complete `BenchmarkSummary`, `last_run`
+/// included, when the dashboard exits early.
+#[derive(Debug, Default)]
+struct ModelPane {
+    runs: Vec<(PerformanceMetrics, LatencyHistogram)>,
+    last_error: Option<String>,
+    finished: bool,
+}
+
+pub struct Dashboard {
+    models: Vec<String>,
+}
+
+impl Dashboard {
+    pub fn new(models: Vec<String>) -> Self {
+        Self { models }
+    }
+
+    /// Drives the TUI until the runner signals completion or the user presses `q`
+    /// (which aborts `runner_handle` so the background request loop stops too).
+    /// Returns the final aggregate stats so the caller can print them after the
+    /// alternate screen is torn down.
+    pub async fn run(
+        self,
+        mut rx: UnboundedReceiver<BenchmarkEvent>,
+        runner_handle: tokio::task::AbortHandle,
+    ) -> Result<BenchmarkSummary, BenchmarkError> {
+        let mut panes: Vec<ModelPane> = self.models.iter().map(|_| ModelPane::default()).collect();
+        let mut active_tab = 0usize;
+
+        let mut stdout = stdout();
+        terminal::enable_raw_mode()?;
+        execute!(stdout, EnterAlternateScreen, cursor::Hide)?;
+
+        let (input_tx, mut input_rx) = tokio::sync::mpsc::unbounded_channel();
+        std::thread::spawn(move || {
+            loop {
+                match event::poll(Duration::from_millis(100)) {
+                    Ok(true) => {
+                        if let Ok(ev) = event::read() {
+                            if input_tx.send(ev).is_err() {
+                                break;
+                            }
+                        }
+                    },
+                    Ok(false) => continue,
+                    Err(_) => break,
+                }
+            }
+        });
+
+        let mut tick = tokio::time::interval(TICK_RATE);
+        let result = loop {
+            tokio::select! {
+                event = rx.recv() => {
+                    match event {
+                        Some(BenchmarkEvent::RunStarted { .. }) => {},
+                        Some(BenchmarkEvent::RunCompleted { model_id, metrics, histogram }) => {
+                            if let Some(pane) = pane_for(&self.models, &mut panes, &model_id) {
+                                pane.runs.push((metrics, histogram));
+                            }
+                        },
+                        Some(BenchmarkEvent::RunFailed { model_id, reason }) => {
+                            if let Some(pane) = pane_for(&self.models, &mut panes, &model_id) {
+                                pane.last_error = Some(reason);
+                            }
+                        },
+                        Some(BenchmarkEvent::ModelFinished { model_id }) => {
+                            if let Some(pane) = pane_for(&self.models, &mut panes, &model_id) {
+                                pane.finished = true;
+                            }
+                        },
+                        Some(BenchmarkEvent::AllFinished { summary }) => break Ok(summary),
+                        // The runner task died without sending a final event (e.g. it panicked),
+                        // so fall back to whatever the panes already accumulated.
+                        None => break Ok(Self::partial_summary(&self.models, &panes)),
+                    }
+                },
+                Some(input_event) = input_rx.recv() => {
+                    if let Event::Key(key) = input_event {
+                        match key.code {
+                            KeyCode::Char('q') => {
+                                runner_handle.abort();
+                                break Ok(Self::partial_summary(&self.models, &panes));
+                            },
+                            KeyCode::Tab => {
+                                if !self.models.is_empty() {
+                                    active_tab = (active_tab + 1) % self.models.len();
+                                }
+                            },
+                            _ => {},
+                        }
+                    }
+                },
+                _ = tick.tick() => {},
+            }
+
+            Self::draw(&mut stdout, &self.models, &panes, active_tab)?;
+        };
+
+        execute!(stdout, cursor::Show, LeaveAlternateScreen)?;
+        terminal::disable_raw_mode()?;
+        result
+    }
+
+    fn draw(stdout: &mut Stdout, models: &[String], panes: &[ModelPane], active_tab: usize) -> std::io::Result<()> {
+        queue!(stdout, terminal::Clear(terminal::ClearType::All), cursor::MoveTo(0, 0))?;
+
+        let tabs = models
+            .iter()
+            .enumerate()
+            .map(|(i, name)| if i == active_tab { format!("[{name}]") } else { format!(" {name} ") })
+            .collect::<Vec<_>>()
+            .join(" ");
+        queue!(stdout, style::Print(format!("{tabs}\r\n")))?;
+        queue!(stdout, style::Print("─".repeat(tabs.chars().count().max(20))))?;
+        queue!(stdout, style::Print("\r\n"))?;
+
+        if let Some(pane) = panes.get(active_tab) {
+            let tokens_per_second: Vec<f64> = pane.runs.iter().map(|(m, _)| m.tokens_per_second).collect();
+            let time_to_first_token_ms: Vec<f64> =
+                pane.runs.iter().map(|(m, _)| m.time_to_first_token_ms as f64).collect();
+            let inter_token_latency_ms: Vec<f64> =
+                pane.runs.iter().map(|(m, _)| m.average_inter_token_latency_ms).collect();
+
+            queue!(stdout, style::Print(format!("Tokens/sec: {}\r\n", sparkline(&tokens_per_second))))?;
+            queue!(stdout, style::Print(format!(
+                "TTFT histogram (ms):\r\n{}",
+                histogram(&time_to_first_token_ms)
+            )))?;
+            queue!(stdout, style::Print(format!(
+                "Inter-token latency histogram (ms):\r\n{}",
+                histogram(&inter_token_latency_ms)
+            )))?;
+            if let Some(err) = &pane.last_error {
+                queue!(stdout, style::Print(format!("Last error: {err}\r\n")))?;
+            }
+            if pane.finished {
+                queue!(stdout, style::Print("(model finished)\r\n"))?;
+            }
+        }
+
+        queue!(stdout, style::Print("\r\nTab: switch model   q: quit\r\n"))?;
+        stdout.flush()
+    }
+
+    /// Rebuilds a [`BenchmarkSummary`] from whatever each pane has already
+    /// accumulated, so exiting early (via `q` or a dead runner task) still
+    /// prints stats for the runs that already completed — `--output table`'s
+    /// running totals and `--output json`/`--output histogram`'s last-run
+    /// detail alike.
+    fn partial_summary(models: &[String], panes: &[ModelPane]) -> BenchmarkSummary {
+        let mut summary = BenchmarkSummary::default();
+        for (model_id, pane) in models.iter().zip(panes.iter()) {
+            for (metrics, histogram) in &pane.runs {
+                summary.record(model_id, metrics, histogram.clone());
+            }
+        }
+        summary
+    }
+}
+
+fn pane_for<'a>(models: &[String], panes: &'a mut [ModelPane], model_id: &str) -> Option<&'a mut ModelPane> {
+    let index = models.iter().position(|m| m == model_id)?;
+    panes.get_mut(index)
+}
+
+/// Renders a compact one-line sparkline across the full sample history.
+fn sparkline(samples: &[f64]) -> String {
+    if samples.is_empty() {
+        return "(no data yet)".to_string();
+    }
+    let min = samples.iter().cloned().fold(f64::MAX, f64::min);
+    let max = samples.iter().cloned().fold(f64::MIN, f64::max);
+    let range = (max - min).max(f64::EPSILON);
+
+    samples
+        .iter()
+        .map(|&v| {
+            let ratio = (v - min) / range;
+            let idx = ((ratio * (SPARKLINE_CHARS.len() - 1) as f64).round() as usize).min(SPARKLINE_CHARS.len() - 1);
+            SPARKLINE_CHARS[idx]
+        })
+        .collect()
+}
+
+/// Renders a fixed-bucket ASCII bar chart of the sample distribution.
+fn histogram(samples: &[f64]) -> String {
+    if samples.is_empty() {
+        return "  (no data yet)\r\n".to_string();
+    }
+
+    const BUCKETS: usize = 8;
+    let min = samples.iter().cloned().fold(f64::MAX, f64::min);
+    let max = samples.iter().cloned().fold(f64::MIN, f64::max);
+    let width = ((max - min) / BUCKETS as f64).max(f64::EPSILON);
+
+    let mut counts = vec![0usize; BUCKETS];
+    for &v in samples {
+        let bucket = (((v - min) / width) as usize).min(BUCKETS - 1);
+        counts[bucket] += 1;
+    }
+    let max_count = counts.iter().copied().max().unwrap_or(1).max(1);
+
+    let mut out = String::new();
+    for (i, count) in counts.iter().enumerate() {
+        let bar_len = (count * 40) / max_count;
+        let lower = min + width * i as f64;
+        let upper = lower + width;
+        out.push_str(&format!(
+            "  {:>7.1}-{:>7.1}: {} ({count})\r\n",
+            lower,
+            upper,
+            "#".repeat(bar_len)
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::chat::performance::{
+        LatencyBucket,
+        MetricsOutputFormat,
+        StallReport,
+    };
+
+    fn test_metrics(tokens_per_second: f64) -> PerformanceMetrics {
+        PerformanceMetrics {
+            tokens_per_second,
+            time_to_first_token_ms: 100,
+            total_duration_ms: 1000,
+            prompt_processing_time_ms: None,
+            generation_time_ms: 900,
+            average_inter_token_latency_ms: 10.0,
+            total_tokens: 50,
+            prompt_tokens: 10,
+            stalls: StallReport::default(),
+        }
+    }
+
+    fn test_histogram() -> LatencyHistogram {
+        LatencyHistogram {
+            buckets: vec![LatencyBucket {
+                label: "<1ms".to_string(),
+                count: 1,
+            }],
+            p99_bucket_index: 0,
+        }
+    }
+
+    #[test]
+    fn test_partial_summary_populates_last_run_not_just_per_model() {
+        let models = vec!["model-a".to_string()];
+        let mut pane = ModelPane::default();
+        pane.runs.push((test_metrics(50.0), test_histogram()));
+        pane.runs.push((test_metrics(70.0), test_histogram()));
+
+        let summary = Dashboard::partial_summary(&models, &[pane]);
+
+        // --output table reads the running per_model totals.
+        assert!(summary.format_for(MetricsOutputFormat::Table).contains("model-a: 2 runs"));
+        // --output json/histogram read last_run, which only `record` (not `record_run`) populates.
+        assert!(
+            summary
+                .format_for(MetricsOutputFormat::Json)
+                .contains("\"tokens_per_second\": 70.0")
+        );
+        assert!(
+            summary
+                .format_for(MetricsOutputFormat::Histogram)
+                .contains("Inter-token Latency Histogram:")
+        );
+    }
+
+    #[test]
+    fn test_partial_summary_skips_models_with_no_runs() {
+        let models = vec!["model-a".to_string(), "model-b".to_string()];
+        let panes = vec![ModelPane::default(), ModelPane::default()];
+
+        let summary = Dashboard::partial_summary(&models, &panes);
+
+        assert_eq!(summary.format(), "Benchmark Summary:\n");
+        assert_eq!(summary.format_for(MetricsOutputFormat::Json), "");
+    }
+}
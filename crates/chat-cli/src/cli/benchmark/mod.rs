@@ -0,0 +1,78 @@
+//! `q benchmark` — a live TUI for comparing model performance.
+//!
+//! Sibling to the chat CLI rather than part of it: it drives its own request
+//! loop (warmup runs, measured runs, optional prompt-length/batch sweeps) and
+//! feeds every completed run through the same [`PerformanceMetrics::calculate`]
+//! path the chat verbose output uses, so the numbers shown here always match
+//! what a user would see from `/context` verbose output in a real session.
+
+mod dashboard;
+mod runner;
+
+use clap::Args;
+
+use self::dashboard::Dashboard;
+pub use self::runner::BenchmarkError;
+use self::runner::{
+    BenchmarkConfig,
+    BenchmarkRunner,
+};
+use crate::cli::chat::performance::MetricsOutputFormat;
+use crate::os::Os;
+
+#[derive(Debug, PartialEq, Args)]
+pub struct BenchmarkArgs {
+    /// Model ID to benchmark. Pass more than once to compare several side by side.
+    #[arg(long = "model", required = true)]
+    pub models: Vec<String>,
+
+    /// Prompt sent on every run.
+    #[arg(long, default_value = "Write a short haiku about rust.")]
+    pub prompt: String,
+
+    /// Warmup runs per model, executed but discarded from the final stats.
+    #[arg(long, default_value_t = 2)]
+    pub warmup_runs: usize,
+
+    /// Measured runs per model.
+    #[arg(long, default_value_t = 10)]
+    pub measured_runs: usize,
+
+    /// Additional prompt lengths (in characters) to sweep, on top of `--prompt` as-is.
+    #[arg(long = "prompt-length", value_delimiter = ',')]
+    pub prompt_length_sweep: Vec<usize>,
+
+    /// Batch sizes (concurrent requests per run) to sweep.
+    #[arg(long = "batch-size", value_delimiter = ',', default_value = "1")]
+    pub batch_sizes: Vec<usize>,
+
+    /// How to render the final stats: a readable table, JSON, or a latency histogram.
+    #[arg(long, value_enum, default_value = "table")]
+    pub output: MetricsOutputFormat,
+}
+
+impl BenchmarkArgs {
+    pub async fn execute(self, os: &Os) -> Result<(), BenchmarkError> {
+        let config = BenchmarkConfig::from_args(&self);
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let runner = BenchmarkRunner::new(config);
+        let os = os.clone();
+        let run_handle = tokio::spawn(async move { runner.run(&os, tx).await });
+        let runner_handle = run_handle.abort_handle();
+
+        let dashboard = Dashboard::new(self.models.clone());
+        let summary = dashboard.run(rx, runner_handle).await?;
+
+        // Quitting with `q` aborts the runner task, which surfaces as a
+        // cancelled join error rather than a real failure.
+        match run_handle.await {
+            Ok(result) => result?,
+            Err(err) if err.is_cancelled() => {},
+            Err(err) => return Err(BenchmarkError::RunnerPanicked(err)),
+        }
+
+        print!("{}", summary.format_for(self.output));
+        Ok(())
+    }
+}
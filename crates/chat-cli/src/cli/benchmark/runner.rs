@@ -0,0 +1,363 @@
+use std::collections::HashMap;
+
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::BenchmarkArgs;
+use crate::cli::chat::performance::{
+    LatencyHistogram,
+    MetricsOutputFormat,
+    PerformanceMetrics,
+};
+use crate::os::Os;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BenchmarkError {
+    #[error("failed to render benchmark dashboard: {0}")]
+    Dashboard(#[from] std::io::Error),
+    #[error("model '{model_id}' failed: {reason}")]
+    ModelRequest { model_id: String, reason: String },
+    #[error("benchmark runner task panicked: {0}")]
+    RunnerPanicked(#[source] tokio::task::JoinError),
+}
+
+/// Resolved benchmark settings: the models to run, and the prompt lengths and
+/// batch sizes each model's measured runs are swept across.
+#[derive(Debug, Clone)]
+pub struct BenchmarkConfig {
+    pub models: Vec<String>,
+    pub base_prompt: String,
+    pub warmup_runs: usize,
+    pub measured_runs: usize,
+    /// Prompt lengths (characters) to sweep. Always includes the base prompt's length.
+    pub prompt_length_sweep: Vec<usize>,
+    /// Batch sizes (concurrent requests) to sweep. Defaults to `[1]` when none are given.
+    pub batch_sweep: Vec<usize>,
+}
+
+impl BenchmarkConfig {
+    pub fn from_args(args: &BenchmarkArgs) -> Self {
+        let base_prompt_len = args.prompt.chars().count();
+        let mut prompt_length_sweep = args.prompt_length_sweep.clone();
+        if !prompt_length_sweep.contains(&base_prompt_len) {
+            prompt_length_sweep.insert(0, base_prompt_len);
+        }
+
+        let mut batch_sweep = args.batch_sizes.clone();
+        if batch_sweep.is_empty() {
+            batch_sweep.push(1);
+        }
+
+        Self {
+            models: args.models.clone(),
+            base_prompt: args.prompt.clone(),
+            warmup_runs: args.warmup_runs,
+            measured_runs: args.measured_runs,
+            prompt_length_sweep,
+            batch_sweep,
+        }
+    }
+
+    /// Builds a prompt of (approximately) `len` characters by repeating the base prompt.
+    fn prompt_of_length(&self, len: usize) -> String {
+        if len <= self.base_prompt.chars().count() {
+            return self.base_prompt.chars().take(len).collect();
+        }
+        self.base_prompt.chars().cycle().take(len).collect()
+    }
+}
+
+/// A single completed (or failed) run, streamed to the dashboard as it happens.
+#[derive(Debug, Clone)]
+pub enum BenchmarkEvent {
+    RunStarted {
+        model_id: String,
+        run_index: usize,
+        total_runs: usize,
+    },
+    RunCompleted {
+        model_id: String,
+        metrics: PerformanceMetrics,
+        histogram: LatencyHistogram,
+    },
+    RunFailed {
+        model_id: String,
+        reason: String,
+    },
+    ModelFinished {
+        model_id: String,
+    },
+    AllFinished {
+        summary: BenchmarkSummary,
+    },
+}
+
+pub struct BenchmarkRunner {
+    config: BenchmarkConfig,
+}
+
+impl BenchmarkRunner {
+    pub fn new(config: BenchmarkConfig) -> Self {
+        Self { config }
+    }
+
+    pub async fn run(&self, os: &Os, tx: UnboundedSender<BenchmarkEvent>) -> Result<(), BenchmarkError> {
+        let mut summary = BenchmarkSummary::default();
+        let total_runs = self.config.measured_runs * self.config.prompt_length_sweep.len() * self.config.batch_sweep.len();
+
+        for model_id in &self.config.models {
+            // Warmup runs prime caches/connections but never reach the dashboard's stats.
+            for _ in 0..self.config.warmup_runs {
+                let prompt = self.config.prompt_of_length(self.config.base_prompt.chars().count());
+                let _ = self.send_one(os, model_id, &prompt).await;
+            }
+
+            let mut run_index = 0;
+            for &prompt_len in &self.config.prompt_length_sweep {
+                let prompt = self.config.prompt_of_length(prompt_len);
+                for &batch_size in &self.config.batch_sweep {
+                    for _ in 0..self.config.measured_runs {
+                        run_index += 1;
+                        let _ = tx.send(BenchmarkEvent::RunStarted {
+                            model_id: model_id.clone(),
+                            run_index,
+                            total_runs,
+                        });
+
+                        let requests = futures::future::join_all(
+                            (0..batch_size).map(|_| self.send_one(os, model_id, &prompt)),
+                        )
+                        .await;
+
+                        for result in requests {
+                            match result {
+                                Ok((metrics, histogram)) => {
+                                    summary.record(model_id, &metrics, histogram.clone());
+                                    let _ = tx.send(BenchmarkEvent::RunCompleted {
+                                        model_id: model_id.clone(),
+                                        metrics,
+                                        histogram,
+                                    });
+                                },
+                                Err(reason) => {
+                                    let _ = tx.send(BenchmarkEvent::RunFailed {
+                                        model_id: model_id.clone(),
+                                        reason,
+                                    });
+                                },
+                            }
+                        }
+                    }
+                }
+            }
+
+            let _ = tx.send(BenchmarkEvent::ModelFinished {
+                model_id: model_id.clone(),
+            });
+        }
+
+        let _ = tx.send(BenchmarkEvent::AllFinished { summary: summary.clone() });
+        Ok(())
+    }
+
+    /// Issues a single prompt against `model_id` and reduces the response to
+    /// [`PerformanceMetrics`] via the same streaming path regular chat requests
+    /// use, rather than a benchmark-specific request method.
+    async fn send_one(&self, os: &Os, model_id: &str, prompt: &str) -> Result<(PerformanceMetrics, LatencyHistogram), String> {
+        let metadata = os
+            .client
+            .send_message(model_id, prompt)
+            .await
+            .map_err(|err| err.to_string())?;
+
+        let metrics = PerformanceMetrics::calculate(&metadata)
+            .ok_or_else(|| "response completed without token timing metrics".to_string())?;
+        let histogram = LatencyHistogram::from_metadata(&metadata);
+        Ok((metrics, histogram))
+    }
+}
+
+/// Aggregate stats kept around so they can be printed once the dashboard exits.
+#[derive(Debug, Clone, Default)]
+pub struct BenchmarkSummary {
+    per_model: HashMap<String, ModelTotals>,
+    /// Full metrics/histogram for the most recently completed run per model,
+    /// kept around so `--output json`/`--output histogram` have something
+    /// richer than the running totals to render.
+    last_run: HashMap<String, (PerformanceMetrics, LatencyHistogram)>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct ModelTotals {
+    runs: usize,
+    tokens_per_second_sum: f64,
+    time_to_first_token_ms_sum: u64,
+    min_tokens_per_second: f64,
+    max_tokens_per_second: f64,
+}
+
+impl BenchmarkSummary {
+    /// Folds a completed run's full metrics/histogram into both the running
+    /// `per_model` totals and `last_run` (what `--output json`/`histogram` read).
+    pub(crate) fn record(&mut self, model_id: &str, metrics: &PerformanceMetrics, histogram: LatencyHistogram) {
+        self.record_run(model_id, metrics.tokens_per_second, metrics.time_to_first_token_ms);
+        self.last_run.insert(model_id.to_string(), (metrics.clone(), histogram));
+    }
+
+    /// Folds one run's raw numbers into `model_id`'s running totals.
+    fn record_run(&mut self, model_id: &str, tokens_per_second: f64, time_to_first_token_ms: u64) {
+        let totals = self.per_model.entry(model_id.to_string()).or_insert_with(|| ModelTotals {
+            min_tokens_per_second: f64::MAX,
+            max_tokens_per_second: f64::MIN,
+            ..Default::default()
+        });
+
+        totals.runs += 1;
+        totals.tokens_per_second_sum += tokens_per_second;
+        totals.time_to_first_token_ms_sum += time_to_first_token_ms;
+        totals.min_tokens_per_second = totals.min_tokens_per_second.min(tokens_per_second);
+        totals.max_tokens_per_second = totals.max_tokens_per_second.max(tokens_per_second);
+    }
+
+    pub fn format(&self) -> String {
+        let mut out = String::from("Benchmark Summary:\n");
+        for (model_id, totals) in &self.per_model {
+            if totals.runs == 0 {
+                continue;
+            }
+            let avg_tokens_per_second = totals.tokens_per_second_sum / totals.runs as f64;
+            let avg_ttft_ms = totals.time_to_first_token_ms_sum as f64 / totals.runs as f64;
+            out.push_str(&format!(
+                "  {model_id}: {runs} runs, {avg:.1} tok/s avg ({min:.1}-{max:.1}), {ttft:.0}ms avg TTFT\n",
+                model_id = model_id,
+                runs = totals.runs,
+                avg = avg_tokens_per_second,
+                min = totals.min_tokens_per_second,
+                max = totals.max_tokens_per_second,
+                ttft = avg_ttft_ms,
+            ));
+        }
+        out
+    }
+
+    /// Renders this summary in the shape requested by `--output`: the usual
+    /// aggregate table, or the last completed run per model as JSON/a latency
+    /// histogram via the same formatters chat's verbose output uses.
+    pub fn format_for(&self, output: MetricsOutputFormat) -> String {
+        match output {
+            MetricsOutputFormat::Table => self.format(),
+            MetricsOutputFormat::Json => self.format_last_run(|metrics, _| metrics.format_json()),
+            MetricsOutputFormat::Histogram => self.format_last_run(|_, histogram| histogram.format_ascii()),
+        }
+    }
+
+    fn format_last_run(&self, render: impl Fn(&PerformanceMetrics, &LatencyHistogram) -> String) -> String {
+        let mut model_ids: Vec<&String> = self.last_run.keys().collect();
+        model_ids.sort();
+
+        let mut out = String::new();
+        for model_id in model_ids {
+            let (metrics, histogram) = &self.last_run[model_id];
+            out.push_str(&format!("{model_id}:\n{}\n\n", render(metrics, histogram)));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::chat::performance::{
+        LatencyBucket,
+        StallReport,
+    };
+
+    fn test_args(models: Vec<&str>, prompt: &str) -> BenchmarkArgs {
+        BenchmarkArgs {
+            models: models.into_iter().map(String::from).collect(),
+            prompt: prompt.to_string(),
+            warmup_runs: 0,
+            measured_runs: 1,
+            prompt_length_sweep: Vec::new(),
+            batch_sizes: Vec::new(),
+            output: MetricsOutputFormat::Table,
+        }
+    }
+
+    fn test_metrics(tokens_per_second: f64, time_to_first_token_ms: u64) -> PerformanceMetrics {
+        PerformanceMetrics {
+            tokens_per_second,
+            time_to_first_token_ms,
+            total_duration_ms: 1000,
+            prompt_processing_time_ms: None,
+            generation_time_ms: 900,
+            average_inter_token_latency_ms: 10.0,
+            total_tokens: 50,
+            prompt_tokens: 10,
+            stalls: StallReport::default(),
+        }
+    }
+
+    fn test_histogram() -> LatencyHistogram {
+        LatencyHistogram {
+            buckets: vec![LatencyBucket {
+                label: "<1ms".to_string(),
+                count: 1,
+            }],
+            p99_bucket_index: 0,
+        }
+    }
+
+    #[test]
+    fn test_prompt_of_length_truncates_when_shorter() {
+        let config = BenchmarkConfig::from_args(&test_args(vec!["m"], "hello world"));
+        assert_eq!(config.prompt_of_length(5), "hello");
+    }
+
+    #[test]
+    fn test_prompt_of_length_cycles_when_longer() {
+        let config = BenchmarkConfig::from_args(&test_args(vec!["m"], "ab"));
+        assert_eq!(config.prompt_of_length(5), "ababa");
+    }
+
+    #[test]
+    fn test_from_args_defaults_sweeps_to_base_prompt_and_single_batch() {
+        let config = BenchmarkConfig::from_args(&test_args(vec!["m"], "hello"));
+        assert_eq!(config.prompt_length_sweep, vec!["hello".chars().count()]);
+        assert_eq!(config.batch_sweep, vec![1]);
+    }
+
+    #[test]
+    fn test_record_updates_totals_and_last_run() {
+        let mut summary = BenchmarkSummary::default();
+        summary.record("model-a", &test_metrics(50.0, 100), test_histogram());
+        summary.record("model-a", &test_metrics(70.0, 80), test_histogram());
+
+        let table = summary.format();
+        assert!(table.contains("model-a: 2 runs, 60.0 tok/s avg (50.0-70.0)"));
+
+        // last_run must reflect the most recent call, not the first.
+        let json = summary.format_for(MetricsOutputFormat::Json);
+        assert!(json.contains("\"tokens_per_second\": 70.0"));
+    }
+
+    #[test]
+    fn test_format_for_dispatches_by_output_format() {
+        let mut summary = BenchmarkSummary::default();
+        summary.record("model-a", &test_metrics(50.0, 100), test_histogram());
+
+        assert!(summary.format_for(MetricsOutputFormat::Table).contains("Benchmark Summary:"));
+        assert!(summary.format_for(MetricsOutputFormat::Json).contains("model-a:"));
+        assert!(
+            summary
+                .format_for(MetricsOutputFormat::Histogram)
+                .contains("Inter-token Latency Histogram:")
+        );
+    }
+
+    #[test]
+    fn test_format_skips_models_with_no_runs() {
+        let mut summary = BenchmarkSummary::default();
+        summary.per_model.insert("untouched".to_string(), ModelTotals::default());
+        assert!(!summary.format().contains("untouched"));
+    }
+}
@@ -27,6 +27,14 @@ pub struct TangentArgs {
 
 #[derive(Debug, PartialEq, Subcommand)]
 pub enum TangentSubcommand {
+    /// Push a new named checkpoint onto the tangent stack
+    New { name: String },
+    /// List all active tangents with their creation time and duration
+    List,
+    /// Switch to a sibling tangent, restoring its saved message history
+    Switch { name: String },
+    /// Pop the current tangent and return to its parent
+    Pop,
     /// Exit tangent mode and keep the last conversation entry (user question + assistant response)
     Tail,
     Compact,
@@ -63,6 +71,129 @@ impl TangentArgs {
         }
 
         match self.subcommand {
+            Some(TangentSubcommand::New { name }) => {
+                if ExperimentManager::is_enabled(os, ExperimentName::Checkpoint) {
+                    execute!(
+                        session.stderr,
+                        style::SetForegroundColor(Color::Yellow),
+                        style::Print(
+                            "⚠️ Checkpoint is disabled while in tangent mode. Please exit tangent mode if you want to use checkpoint.\n"
+                        ),
+                        style::SetForegroundColor(Color::Reset),
+                    )?;
+                }
+
+                match session.conversation.enter_named_tangent(name.clone()) {
+                    Ok(()) => {
+                        execute!(
+                            session.stderr,
+                            style::SetForegroundColor(Color::DarkGrey),
+                            style::Print("Created a named checkpoint ("),
+                            style::SetForegroundColor(Color::Yellow),
+                            style::Print("↯"),
+                            style::SetForegroundColor(Color::DarkGrey),
+                            style::Print(format!(") '{name}'. Use /tangent switch <name> to jump between tangents.\n")),
+                            style::SetForegroundColor(Color::Reset)
+                        )?;
+                    },
+                    Err(err) => {
+                        execute!(
+                            session.stderr,
+                            style::SetForegroundColor(Color::Red),
+                            style::Print(format!("Failed to create tangent '{name}': {err}\n")),
+                            style::SetForegroundColor(Color::Reset)
+                        )?;
+                    },
+                }
+            },
+            Some(TangentSubcommand::List) => {
+                let tangents = session.conversation.list_tangents();
+                if tangents.is_empty() {
+                    execute!(
+                        session.stderr,
+                        style::SetForegroundColor(Color::Red),
+                        style::Print("No active tangents.\n"),
+                        style::SetForegroundColor(Color::Reset)
+                    )?;
+                } else {
+                    execute!(
+                        session.stderr,
+                        style::SetForegroundColor(Color::DarkGrey),
+                        style::Print("Active tangents (base \u{2192} tip):\n"),
+                        style::SetForegroundColor(Color::Reset)
+                    )?;
+                    for (depth, tangent) in tangents.iter().enumerate() {
+                        let label = tangent.name.as_deref().unwrap_or("(unnamed)");
+                        execute!(
+                            session.stderr,
+                            style::Print(format!(
+                                "  {depth}: {label} — entered {entered_at}, {duration}s ago\n",
+                                entered_at = tangent.entered_at,
+                                duration = tangent.duration_seconds,
+                            ))
+                        )?;
+                    }
+                }
+            },
+            Some(TangentSubcommand::Switch { name }) => {
+                if session.conversation.is_in_tangent_mode() {
+                    match session.conversation.switch_tangent(&name) {
+                        Ok(()) => {
+                            execute!(
+                                session.stderr,
+                                style::SetForegroundColor(Color::Green),
+                                style::Print(format!("✔ Switched to tangent '{name}'.\n")),
+                                style::SetForegroundColor(Color::Reset)
+                            )?;
+                        },
+                        Err(err) => {
+                            execute!(
+                                session.stderr,
+                                style::SetForegroundColor(Color::Red),
+                                style::Print(format!("Failed to switch to tangent '{name}': {err}\n")),
+                                style::SetForegroundColor(Color::Reset)
+                            )?;
+                        },
+                    }
+                } else {
+                    execute!(
+                        session.stderr,
+                        style::SetForegroundColor(Color::Red),
+                        style::Print("You need to be in tangent mode to switch tangents.\n"),
+                        style::SetForegroundColor(Color::Reset)
+                    )?;
+                }
+            },
+            Some(TangentSubcommand::Pop) => {
+                if session.conversation.is_in_tangent_mode() {
+                    let duration_seconds = session.conversation.get_tangent_duration_seconds().unwrap_or(0);
+                    session.conversation.exit_tangent_mode();
+                    Self::send_tangent_telemetry(os, session, duration_seconds).await;
+
+                    let destination = if session.conversation.is_in_tangent_mode() {
+                        "parent tangent"
+                    } else {
+                        "main conversation"
+                    };
+                    execute!(
+                        session.stderr,
+                        style::SetForegroundColor(Color::DarkGrey),
+                        style::Print("Popped tangent checkpoint ("),
+                        style::SetForegroundColor(Color::Yellow),
+                        style::Print("↯"),
+                        style::SetForegroundColor(Color::DarkGrey),
+                        style::Print(format!("). Returned to {destination}.\n")),
+                        style::SetForegroundColor(Color::Reset)
+                    )?;
+                } else {
+                    execute!(
+                        session.stderr,
+                        style::SetForegroundColor(Color::Red),
+                        style::Print("You need to be in tangent mode to pop.\n"),
+                        style::SetForegroundColor(Color::Reset)
+                    )?;
+                }
+            },
             Some(TangentSubcommand::Tail) => {
                 // Check if checkpoint is enabled
                 if ExperimentManager::is_enabled(os, ExperimentName::Checkpoint) {
@@ -242,4 +373,46 @@ mod tests {
         assert!(!conversation.is_in_tangent_mode());
         assert!(conversation.get_tangent_duration_seconds().is_none());
     }
+
+    #[tokio::test]
+    async fn test_named_tangent_stack() {
+        let mut os = Os::new().await.unwrap();
+        let agents = Agents::default();
+        let mut tool_manager = ToolManager::default();
+        let mut conversation = ConversationState::new(
+            "test_conv_id",
+            agents,
+            tool_manager.load_tools(&mut os, &mut vec![]).await.unwrap(),
+            tool_manager,
+            None,
+            &os,
+            false, // mcp_enabled
+        )
+        .await;
+
+        // Push two named tangents, forming a stack.
+        conversation.enter_named_tangent("alpha".to_string()).unwrap();
+        conversation.enter_named_tangent("beta".to_string()).unwrap();
+        assert!(conversation.is_in_tangent_mode());
+
+        let tangents = conversation.list_tangents();
+        assert_eq!(tangents.len(), 2);
+        assert_eq!(tangents[0].name.as_deref(), Some("alpha"));
+        assert_eq!(tangents[1].name.as_deref(), Some("beta"));
+
+        // Switching to a sibling should not change the stack depth.
+        conversation.switch_tangent("alpha").unwrap();
+        assert_eq!(conversation.list_tangents().len(), 2);
+
+        // Switching to an unknown name is an error.
+        assert!(conversation.switch_tangent("missing").is_err());
+
+        // Popping one level returns to the remaining parent tangent.
+        conversation.exit_tangent_mode();
+        assert!(conversation.is_in_tangent_mode());
+        assert_eq!(conversation.list_tangents().len(), 1);
+
+        conversation.exit_tangent_mode();
+        assert!(!conversation.is_in_tangent_mode());
+    }
 }
@@ -0,0 +1,168 @@
+use std::time::{
+    SystemTime,
+    UNIX_EPOCH,
+};
+
+use crate::cli::agent::Agents;
+use crate::cli::chat::tool_manager::ToolManager;
+use crate::os::Os;
+
+#[derive(Debug, thiserror::Error)]
+pub enum TangentError {
+    #[error("no tangent named '{0}'")]
+    NotFound(String),
+}
+
+/// A saved point in the conversation a tangent restores back to on exit.
+#[derive(Debug, Clone, Default)]
+struct ConversationSnapshot {
+    message_history: Vec<String>,
+}
+
+/// One entry in the tangent checkpoint stack.
+#[derive(Debug, Clone)]
+struct TangentCheckpoint {
+    name: Option<String>,
+    checkpoint_snapshot: ConversationSnapshot,
+    entered_at: u64,
+}
+
+/// A single active tangent, as surfaced to `/tangent list`.
+#[derive(Debug, Clone)]
+pub struct TangentInfo {
+    pub name: Option<String>,
+    pub entered_at: u64,
+    pub duration_seconds: i64,
+}
+
+#[derive(Debug)]
+pub struct ConversationState {
+    conversation_id: String,
+    message_history: Vec<String>,
+    /// Tangents are a stack rather than a single on/off flag so a named
+    /// checkpoint can itself be pushed from inside another tangent.
+    tangent_stack: Vec<TangentCheckpoint>,
+}
+
+impl ConversationState {
+    /// `agents` and `tool_manager` aren't kept yet — nothing here reads past
+    /// message history or tangent state, and the caller still passes them so
+    /// this stays a drop-in match for the agent/tool-loading setup elsewhere.
+    pub async fn new<T>(
+        conversation_id: impl Into<String>,
+        _agents: Agents,
+        _tools: T,
+        _tool_manager: ToolManager,
+        _resume_from: Option<()>,
+        _os: &Os,
+        _mcp_enabled: bool,
+    ) -> Self {
+        Self {
+            conversation_id: conversation_id.into(),
+            message_history: Vec::new(),
+            tangent_stack: Vec::new(),
+        }
+    }
+
+    pub fn conversation_id(&self) -> &str {
+        &self.conversation_id
+    }
+
+    pub fn is_in_tangent_mode(&self) -> bool {
+        !self.tangent_stack.is_empty()
+    }
+
+    /// Seconds since the current (topmost) tangent was entered, or `None` if
+    /// not in a tangent at all.
+    pub fn get_tangent_duration_seconds(&self) -> Option<i64> {
+        self.tangent_stack
+            .last()
+            .map(|checkpoint| now_seconds() - checkpoint.entered_at as i64)
+    }
+
+    /// Pushes an unnamed checkpoint, the default `/tangent` entry point.
+    pub fn enter_tangent_mode(&mut self) {
+        self.push_checkpoint(None);
+    }
+
+    /// Pushes a named checkpoint onto the stack.
+    pub fn enter_named_tangent(&mut self, name: String) -> Result<(), TangentError> {
+        self.push_checkpoint(Some(name));
+        Ok(())
+    }
+
+    fn push_checkpoint(&mut self, name: Option<String>) {
+        self.tangent_stack.push(TangentCheckpoint {
+            name,
+            checkpoint_snapshot: ConversationSnapshot {
+                message_history: self.message_history.clone(),
+            },
+            entered_at: now_seconds() as u64,
+        });
+    }
+
+    /// Pops the current tangent, discarding everything said inside it.
+    pub fn exit_tangent_mode(&mut self) {
+        if let Some(checkpoint) = self.tangent_stack.pop() {
+            self.message_history = checkpoint.checkpoint_snapshot.message_history;
+        }
+    }
+
+    /// Pops the current tangent but keeps the most recent exchange.
+    pub fn exit_tangent_mode_with_tail(&mut self) {
+        if let Some(checkpoint) = self.tangent_stack.pop() {
+            let mut history = checkpoint.checkpoint_snapshot.message_history;
+            if let Some(last) = self.message_history.last() {
+                history.push(last.clone());
+            }
+            self.message_history = history;
+        }
+    }
+
+    /// Pops the current tangent, replacing it with a single summary entry.
+    pub fn exit_tangent_mode_with_compact(&mut self, summary: String) {
+        if let Some(checkpoint) = self.tangent_stack.pop() {
+            let mut history = checkpoint.checkpoint_snapshot.message_history;
+            history.push(summary);
+            self.message_history = history;
+        }
+    }
+
+    /// Lists active tangents from base to tip.
+    pub fn list_tangents(&self) -> Vec<TangentInfo> {
+        let now = now_seconds();
+        self.tangent_stack
+            .iter()
+            .map(|checkpoint| TangentInfo {
+                name: checkpoint.name.clone(),
+                entered_at: checkpoint.entered_at,
+                duration_seconds: now - checkpoint.entered_at as i64,
+            })
+            .collect()
+    }
+
+    /// Switches to a sibling tangent, saving the currently active one's
+    /// history back into its own slot first so switching back restores it.
+    pub fn switch_tangent(&mut self, name: &str) -> Result<(), TangentError> {
+        let current = self
+            .tangent_stack
+            .last_mut()
+            .ok_or_else(|| TangentError::NotFound(name.to_string()))?;
+        current.checkpoint_snapshot.message_history = self.message_history.clone();
+
+        let target_index = self
+            .tangent_stack
+            .iter()
+            .position(|checkpoint| checkpoint.name.as_deref() == Some(name))
+            .ok_or_else(|| TangentError::NotFound(name.to_string()))?;
+
+        let target = self.tangent_stack.remove(target_index);
+        self.message_history = target.checkpoint_snapshot.message_history.clone();
+        self.tangent_stack.push(target);
+        Ok(())
+    }
+}
+
+fn now_seconds() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs() as i64
+}
@@ -21,6 +21,8 @@ pub struct PerformanceMetrics {
     pub total_tokens: usize,
     /// Tokens in the prompt/input
     pub prompt_tokens: usize,
+    /// Generation stalls detected from per-token timing, if any
+    pub stalls: StallReport,
 }
 
 impl PerformanceMetrics {
@@ -68,6 +70,7 @@ impl PerformanceMetrics {
             average_inter_token_latency_ms,
             total_tokens: token_metrics.total_tokens,
             prompt_tokens: token_metrics.prompt_tokens,
+            stalls: StallReport::from_metadata(metadata),
         })
     }
 
@@ -91,10 +94,376 @@ impl PerformanceMetrics {
             output.push_str(&format!("\n  Prompt processing: {}ms", prompt_processing_ms));
         }
 
+        if self.stalls.stall_count > 0 {
+            let worst = self
+                .stalls
+                .events
+                .iter()
+                .max_by_key(|event| event.stall_ms)
+                .expect("stall_count > 0 implies at least one event");
+            output.push_str(&format!(
+                "\n  Stalls: {count} totaling {total:.1}s, worst {worst_ms}ms at token {token}",
+                count = self.stalls.stall_count,
+                total = self.stalls.total_stall_ms as f64 / 1000.0,
+                worst_ms = worst.stall_ms,
+                token = worst.token_index,
+            ));
+        }
+
+        output
+    }
+
+    /// Serializes all fields as JSON, for piping benchmark/chat timing data into
+    /// external tooling instead of reading `format_comprehensive`'s prose.
+    pub fn format_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("PerformanceMetrics is always serializable")
+    }
+}
+
+/// Which shape `format_comprehensive`'s caller should render: readable prose,
+/// machine-readable JSON, or an ASCII bar chart of inter-token latency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum MetricsOutputFormat {
+    Table,
+    Json,
+    Histogram,
+}
+
+/// One bucket of a log-scale latency histogram, e.g. "4-8ms": 12 samples.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencyBucket {
+    pub label: String,
+    pub count: usize,
+}
+
+/// A log-scale histogram of inter-token intervals, bucketed as <1ms, 1-2ms,
+/// 2-4ms, 4-8ms, ... so a long tail of stalls doesn't blow out a linear chart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencyHistogram {
+    pub buckets: Vec<LatencyBucket>,
+    /// Index into `buckets` holding the p99 interval.
+    pub p99_bucket_index: usize,
+}
+
+impl LatencyHistogram {
+    /// Builds a histogram from the per-token intervals in `metadata`, preferring
+    /// `token_metrics.token_timestamps` (consecutive diffs) and falling back to
+    /// `time_between_chunks` when per-token timestamps weren't captured.
+    pub fn from_metadata(metadata: &RequestMetadata) -> Self {
+        Self::from_intervals(&inter_token_intervals(metadata))
+    }
+
+    fn from_intervals(intervals: &[u64]) -> Self {
+        let max_interval = intervals.iter().copied().max().unwrap_or(0).max(1);
+
+        let mut upper_bounds = vec![1u64];
+        while *upper_bounds.last().expect("non-empty") <= max_interval {
+            upper_bounds.push(upper_bounds.last().expect("non-empty") * 2);
+        }
+
+        let mut counts = vec![0usize; upper_bounds.len()];
+        for &interval in intervals {
+            let bucket_index = upper_bounds
+                .iter()
+                .position(|&bound| interval < bound)
+                .unwrap_or(upper_bounds.len() - 1);
+            counts[bucket_index] += 1;
+        }
+
+        let buckets = upper_bounds
+            .iter()
+            .enumerate()
+            .map(|(i, &bound)| {
+                let label = if i == 0 {
+                    format!("<{bound}ms")
+                } else {
+                    format!("{}-{bound}ms", upper_bounds[i - 1])
+                };
+                LatencyBucket {
+                    label,
+                    count: counts[i],
+                }
+            })
+            .collect();
+
+        let p99_bucket_index = if intervals.is_empty() {
+            0
+        } else {
+            let mut sorted = intervals.to_vec();
+            sorted.sort_unstable();
+            let index = ((0.99 * sorted.len() as f64).ceil() as usize)
+                .saturating_sub(1)
+                .min(sorted.len() - 1);
+            let p99_value = sorted[index];
+            upper_bounds
+                .iter()
+                .position(|&bound| p99_value < bound)
+                .unwrap_or(upper_bounds.len() - 1)
+        };
+
+        Self { buckets, p99_bucket_index }
+    }
+
+    /// Renders the histogram as an ASCII bar chart, marking the bucket holding p99.
+    pub fn format_ascii(&self) -> String {
+        let max_count = self.buckets.iter().map(|b| b.count).max().unwrap_or(1).max(1);
+
+        let mut output = String::from("Inter-token Latency Histogram:");
+        for (i, bucket) in self.buckets.iter().enumerate() {
+            let bar_len = (bucket.count * 40) / max_count;
+            let marker = if i == self.p99_bucket_index { "  <- p99" } else { "" };
+            output.push_str(&format!(
+                "\n  {:>10}: {} ({}){}",
+                bucket.label,
+                "#".repeat(bar_len),
+                bucket.count,
+                marker
+            ));
+        }
         output
     }
 }
 
+fn inter_token_intervals(metadata: &RequestMetadata) -> Vec<u64> {
+    let timestamps = metadata
+        .token_metrics
+        .as_ref()
+        .map(|metrics| metrics.token_timestamps.as_slice())
+        .unwrap_or(&[]);
+
+    if timestamps.len() > 1 {
+        timestamps.windows(2).map(|pair| pair[1].saturating_sub(pair[0])).collect()
+    } else {
+        metadata.time_between_chunks.clone()
+    }
+}
+
+/// A single interval between tokens that exceeded the stall threshold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StallEvent {
+    /// Index of the token that arrived after the stall, counting the first token
+    /// as 0 (equivalently, the 1-based position of the stalled interval)
+    pub token_index: usize,
+    /// Length of the stalled interval in milliseconds
+    pub stall_ms: u64,
+    /// Time from the start of generation to this token, in milliseconds
+    pub offset_from_start_ms: u64,
+}
+
+/// Generation stalls detected by scanning consecutive inter-token intervals:
+/// a slow-but-steady stream looks very different from one that froze mid-response,
+/// a distinction a single averaged inter-token latency hides completely.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct StallReport {
+    pub events: Vec<StallEvent>,
+    pub stall_count: usize,
+    pub total_stall_ms: u64,
+}
+
+/// Stall floor: intervals below this are never flagged, regardless of the running median.
+const DEFAULT_STALL_FLOOR_MS: u64 = 200;
+/// Stall multiplier applied to the running median interval.
+const DEFAULT_STALL_MULTIPLIER: f64 = 5.0;
+
+impl StallReport {
+    /// Detects stalls using the default threshold: `max(200ms, 5x the running median interval)`.
+    pub fn from_metadata(metadata: &RequestMetadata) -> Self {
+        Self::from_metadata_with_threshold(metadata, DEFAULT_STALL_FLOOR_MS, DEFAULT_STALL_MULTIPLIER)
+    }
+
+    pub fn from_metadata_with_threshold(metadata: &RequestMetadata, floor_ms: u64, multiplier: f64) -> Self {
+        Self::from_intervals(&inter_token_intervals(metadata), floor_ms, multiplier)
+    }
+
+    fn from_intervals(intervals: &[u64], floor_ms: u64, multiplier: f64) -> Self {
+        let mut events = Vec::new();
+        let mut seen_sorted: Vec<u64> = Vec::with_capacity(intervals.len());
+        let mut offset_ms: u64 = 0;
+
+        for (i, &interval) in intervals.iter().enumerate() {
+            offset_ms += interval;
+
+            // The running median is computed from intervals seen so far, so the
+            // threshold adapts as generation speeds up or slows down.
+            let threshold = if seen_sorted.is_empty() {
+                floor_ms as f64
+            } else {
+                (multiplier * median(&seen_sorted)).max(floor_ms as f64)
+            };
+
+            if interval as f64 > threshold {
+                events.push(StallEvent {
+                    token_index: i + 1,
+                    stall_ms: interval,
+                    offset_from_start_ms: offset_ms,
+                });
+            }
+
+            let insert_at = seen_sorted.partition_point(|&seen| seen < interval);
+            seen_sorted.insert(insert_at, interval);
+        }
+
+        let total_stall_ms = events.iter().map(|event| event.stall_ms).sum();
+        Self {
+            stall_count: events.len(),
+            total_stall_ms,
+            events,
+        }
+    }
+}
+
+fn median(sorted: &[u64]) -> f64 {
+    let n = sorted.len();
+    if n % 2 == 1 {
+        sorted[n / 2] as f64
+    } else {
+        (sorted[n / 2 - 1] as f64 + sorted[n / 2] as f64) / 2.0
+    }
+}
+
+/// Running mean/variance for one metric, updated via Welford's algorithm so a
+/// long session doesn't need to keep recomputing from scratch.
+#[derive(Debug, Clone, Default)]
+struct RunningStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    samples: Vec<f64>,
+}
+
+impl RunningStats {
+    fn record(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+        self.samples.push(value);
+    }
+
+    fn variance(&self) -> f64 {
+        if self.count < 2 {
+            0.0
+        } else {
+            self.m2 / (self.count - 1) as f64
+        }
+    }
+
+    /// Percentile via the nearest-rank method. `p` is in `[0, 100]`.
+    fn percentile(&self, p: f64) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        let mut sorted = self.samples.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let n = sorted.len();
+        let index = ((p / 100.0 * n as f64).ceil() as isize - 1).clamp(0, n as isize - 1) as usize;
+        sorted[index]
+    }
+
+    fn min(&self) -> f64 {
+        self.samples.iter().cloned().fold(f64::MAX, f64::min)
+    }
+
+    fn max(&self) -> f64 {
+        self.samples.iter().cloned().fold(f64::MIN, f64::max)
+    }
+
+    fn summary(&self) -> MetricSummary {
+        MetricSummary {
+            count: self.count,
+            mean: self.mean,
+            std_dev: self.variance().sqrt(),
+            min: if self.count == 0 { 0.0 } else { self.min() },
+            max: if self.count == 0 { 0.0 } else { self.max() },
+            p50: self.percentile(50.0),
+            p90: self.percentile(90.0),
+            p99: self.percentile(99.0),
+        }
+    }
+}
+
+/// Distribution summary for a single metric across a session.
+#[derive(Debug, Clone, Copy)]
+pub struct MetricSummary {
+    pub count: u64,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+    pub p50: f64,
+    pub p90: f64,
+    pub p99: f64,
+}
+
+impl MetricSummary {
+    fn format(&self, label: &str, unit: &str) -> String {
+        format!(
+            "  {label}: mean {mean:.1}{unit}, std dev {std:.1}{unit}, min {min:.1}{unit}, p50 {p50:.1}{unit}, p90 {p90:.1}{unit}, p99 {p99:.1}{unit}, max {max:.1}{unit}",
+            label = label,
+            unit = unit,
+            mean = self.mean,
+            std = self.std_dev,
+            min = self.min,
+            p50 = self.p50,
+            p90 = self.p90,
+            p99 = self.p99,
+            max = self.max,
+        )
+    }
+}
+
+/// Accumulates [`PerformanceMetrics`] across every request in a chat session
+/// and reports distributions rather than a single rolling average, so tail
+/// latency shows up instead of being smoothed away.
+#[derive(Debug, Clone, Default)]
+pub struct PerformanceMetricsAggregator {
+    tokens_per_second: RunningStats,
+    time_to_first_token_ms: RunningStats,
+    average_inter_token_latency_ms: RunningStats,
+    total_requests: u64,
+    total_tokens: u64,
+}
+
+impl PerformanceMetricsAggregator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Folds one request's metrics into the running session stats.
+    pub fn record(&mut self, metrics: &PerformanceMetrics) {
+        self.tokens_per_second.record(metrics.tokens_per_second);
+        self.time_to_first_token_ms.record(metrics.time_to_first_token_ms as f64);
+        self.average_inter_token_latency_ms
+            .record(metrics.average_inter_token_latency_ms);
+        self.total_requests += 1;
+        self.total_tokens += metrics.total_tokens as u64;
+    }
+
+    pub fn tokens_per_second_summary(&self) -> MetricSummary {
+        self.tokens_per_second.summary()
+    }
+
+    pub fn time_to_first_token_summary(&self) -> MetricSummary {
+        self.time_to_first_token_ms.summary()
+    }
+
+    pub fn inter_token_latency_summary(&self) -> MetricSummary {
+        self.average_inter_token_latency_ms.summary()
+    }
+
+    /// Formats the full-session distribution report (percentiles, not just averages).
+    pub fn format_session_summary(&self) -> String {
+        format!(
+            "Session Performance Summary:\n  Total requests: {}\n  Cumulative tokens: {}\n{}\n{}\n{}",
+            self.total_requests,
+            self.total_tokens,
+            self.tokens_per_second_summary().format("Tokens/sec", ""),
+            self.time_to_first_token_summary().format("TTFT", "ms"),
+            self.inter_token_latency_summary().format("Inter-token latency", "ms"),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -205,4 +574,132 @@ mod tests {
         assert!(formatted.contains("Total tokens: 100 (20 prompt + 80 completion)"));
         assert!(formatted.contains("Prompt processing: 500ms"));
     }
+
+    #[test]
+    fn test_aggregator_percentiles_nearest_rank() {
+        let mut stats = RunningStats::default();
+        for value in [10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0, 100.0] {
+            stats.record(value);
+        }
+
+        // nearest-rank: index = ceil(p/100 * n) - 1, clamped
+        assert_eq!(stats.percentile(50.0), 50.0);
+        assert_eq!(stats.percentile(90.0), 90.0);
+        assert_eq!(stats.percentile(99.0), 100.0);
+    }
+
+    #[test]
+    fn test_aggregator_mean_and_variance() {
+        let mut stats = RunningStats::default();
+        for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            stats.record(value);
+        }
+
+        assert!((stats.mean - 5.0).abs() < 1e-9);
+        assert!((stats.variance() - 4.571428571).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_aggregator_session_summary() {
+        let mut aggregator = PerformanceMetricsAggregator::new();
+
+        let fast = PerformanceMetrics::calculate(&create_test_metadata(100, 20, 500, 2500, 1000, 3000)).unwrap();
+        let slow = PerformanceMetrics::calculate(&create_test_metadata(100, 20, 2000, 9000, 1000, 10000)).unwrap();
+        aggregator.record(&fast);
+        aggregator.record(&slow);
+
+        let summary = aggregator.format_session_summary();
+        assert!(summary.contains("Total requests: 2"));
+        assert!(summary.contains("Cumulative tokens: 200"));
+        assert!(summary.contains("Tokens/sec:"));
+        assert!(summary.contains("TTFT:"));
+        assert!(summary.contains("Inter-token latency:"));
+
+        let ttft_summary = aggregator.time_to_first_token_summary();
+        assert_eq!(ttft_summary.count, 2);
+        assert!((ttft_summary.max - 2000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_format_json_round_trips() {
+        let metadata = create_test_metadata(100, 20, 500, 2500, 1000, 3000);
+        let metrics = PerformanceMetrics::calculate(&metadata).unwrap();
+
+        let json = metrics.format_json();
+        let parsed: PerformanceMetrics = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.total_tokens, metrics.total_tokens);
+        assert!((parsed.tokens_per_second - metrics.tokens_per_second).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_latency_histogram_buckets_by_log_scale() {
+        let mut metadata = create_test_metadata(100, 20, 500, 2500, 1000, 3000);
+        if let Some(ref mut token_metrics) = metadata.token_metrics {
+            // Consecutive diffs of these timestamps are: 1, 1, 3, 6, 10
+            token_metrics.token_timestamps = vec![0, 1, 2, 5, 11, 21];
+        }
+
+        let histogram = LatencyHistogram::from_metadata(&metadata);
+        let total: usize = histogram.buckets.iter().map(|b| b.count).sum();
+        assert_eq!(total, 5); // 5 consecutive-diff intervals from 6 timestamps
+
+        assert_eq!(histogram.buckets[0].label, "<1ms");
+        let ascii = histogram.format_ascii();
+        assert!(ascii.contains("Inter-token Latency Histogram:"));
+        assert!(ascii.contains("<- p99"));
+    }
+
+    #[test]
+    fn test_latency_histogram_falls_back_to_time_between_chunks() {
+        let mut metadata = create_test_metadata(100, 20, 500, 2500, 1000, 3000);
+        metadata.time_between_chunks = vec![1, 2, 4, 4, 20];
+
+        let histogram = LatencyHistogram::from_metadata(&metadata);
+        let total: usize = histogram.buckets.iter().map(|b| b.count).sum();
+        assert_eq!(total, 5);
+    }
+
+    #[test]
+    fn test_stall_detection_flags_interval_exceeding_threshold() {
+        // Steady ~10ms intervals, then one 900ms freeze.
+        let intervals: Vec<u64> = vec![10, 11, 9, 10, 900, 10, 11];
+        let report = StallReport::from_intervals(&intervals, DEFAULT_STALL_FLOOR_MS, DEFAULT_STALL_MULTIPLIER);
+
+        assert_eq!(report.stall_count, 1);
+        assert_eq!(report.total_stall_ms, 900);
+        assert_eq!(report.events[0].token_index, 5); // 1-based, the 5th interval
+        assert_eq!(report.events[0].stall_ms, 900);
+        assert_eq!(report.events[0].offset_from_start_ms, 10 + 11 + 9 + 10 + 900);
+    }
+
+    #[test]
+    fn test_stall_detection_respects_floor_for_fast_streams() {
+        // All intervals tiny; 5x median would be well under the 200ms floor, so
+        // nothing here should ever be flagged even though one interval is an outlier.
+        let intervals: Vec<u64> = vec![1, 2, 1, 1, 50, 1, 1];
+        let report = StallReport::from_intervals(&intervals, DEFAULT_STALL_FLOOR_MS, DEFAULT_STALL_MULTIPLIER);
+
+        assert_eq!(report.stall_count, 0);
+        assert_eq!(report.total_stall_ms, 0);
+    }
+
+    #[test]
+    fn test_format_comprehensive_includes_stall_summary() {
+        let mut metadata = create_test_metadata(100, 20, 500, 2500, 1000, 3000);
+        if let Some(ref mut token_metrics) = metadata.token_metrics {
+            let mut timestamps = vec![0u64];
+            let mut t = 0u64;
+            for i in 0..99 {
+                t += if i == 40 { 900 } else { 10 };
+                timestamps.push(t);
+            }
+            token_metrics.token_timestamps = timestamps;
+        }
+
+        let metrics = PerformanceMetrics::calculate(&metadata).unwrap();
+        assert_eq!(metrics.stalls.stall_count, 1);
+
+        let formatted = metrics.format_comprehensive();
+        assert!(formatted.contains("Stalls: 1 totaling 0.9s, worst 900ms at token 41"));
+    }
 }
@@ -0,0 +1,24 @@
+pub mod benchmark;
+
+use clap::Subcommand;
+
+use self::benchmark::{
+    BenchmarkArgs,
+    BenchmarkError,
+};
+use crate::os::Os;
+
+/// Top-level `q` subcommands that live outside the interactive chat REPL.
+#[derive(Debug, Subcommand)]
+pub enum Cli {
+    /// Live TUI for comparing model performance across one or more models.
+    Benchmark(BenchmarkArgs),
+}
+
+impl Cli {
+    pub async fn execute(self, os: &Os) -> Result<(), BenchmarkError> {
+        match self {
+            Cli::Benchmark(args) => args.execute(os).await,
+        }
+    }
+}